@@ -26,6 +26,7 @@ extern crate alloc;
 use alloc::vec::Vec;
 use core::{
 	convert::{TryFrom, TryInto},
+	marker::PhantomData,
 	result::Result,
 };
 use derivative::Derivative;
@@ -97,6 +98,27 @@ impl TryFrom<VersionedMultiLocation> for v1::MultiLocation {
 	}
 }
 
+impl VersionedMultiLocation {
+	/// The XCM version number of the contained value.
+	pub fn identify_version(&self) -> u32 {
+		match self {
+			Self::V0(_) => 0,
+			Self::V1(_) => 1,
+		}
+	}
+
+	/// Convert the contained value to XCM version `target`, re-wrapping it in the matching variant.
+	///
+	/// Returns `Err` if `target` is not a known version or the value cannot be expressed in it.
+	pub fn into_version(self, target: u32) -> Result<Self, ()> {
+		Ok(match target {
+			0 => Self::V0(self.try_into()?),
+			1 => Self::V1(self.try_into()?),
+			_ => return Err(()),
+		})
+	}
+}
+
 /// A single `MultiAsset` value, together with its version code.
 #[derive(Derivative, Encode, Decode)]
 #[derivative(Clone(bound = ""), Eq(bound = ""), PartialEq(bound = ""), Debug(bound = ""))]
@@ -141,6 +163,27 @@ impl TryFrom<VersionedMultiAsset> for v1::MultiAsset {
 	}
 }
 
+impl VersionedMultiAsset {
+	/// The XCM version number of the contained value.
+	pub fn identify_version(&self) -> u32 {
+		match self {
+			Self::V0(_) => 0,
+			Self::V1(_) => 1,
+		}
+	}
+
+	/// Convert the contained value to XCM version `target`, re-wrapping it in the matching variant.
+	///
+	/// Returns `Err` if `target` is not a known version or the value cannot be expressed in it.
+	pub fn into_version(self, target: u32) -> Result<Self, ()> {
+		Ok(match target {
+			0 => Self::V0(self.try_into()?),
+			1 => Self::V1(self.try_into()?),
+			_ => return Err(()),
+		})
+	}
+}
+
 /// A single `MultiAssets` value, together with its version code.
 ///
 /// NOTE: For XCM v0, this was `Vec<MultiAsset>`.
@@ -187,6 +230,27 @@ impl TryFrom<VersionedMultiAssets> for v1::MultiAssets {
 	}
 }
 
+impl VersionedMultiAssets {
+	/// The XCM version number of the contained value.
+	pub fn identify_version(&self) -> u32 {
+		match self {
+			Self::V0(_) => 0,
+			Self::V1(_) => 1,
+		}
+	}
+
+	/// Convert the contained value to XCM version `target`, re-wrapping it in the matching variant.
+	///
+	/// Returns `Err` if `target` is not a known version or the value cannot be expressed in it.
+	pub fn into_version(self, target: u32) -> Result<Self, ()> {
+		Ok(match target {
+			0 => Self::V0(self.try_into()?),
+			1 => Self::V1(self.try_into()?),
+			_ => return Err(()),
+		})
+	}
+}
+
 /// A single XCM message, together with its version code.
 #[derive(Derivative, Encode, Decode)]
 #[derivative(Clone(bound = ""), Eq(bound = ""), PartialEq(bound = ""), Debug(bound = ""))]
@@ -229,6 +293,39 @@ impl<Call> TryFrom<VersionedXcm<Call>> for v1::Xcm<Call> {
 	}
 }
 
+/// Determine the XCM version that a given destination is known to understand.
+///
+/// This is the discovery half of version negotiation. Whereas `WrapVersion` both discovers the
+/// version of a destination *and* re-encodes a message for it, `GetVersion` exposes only the
+/// lookup, so that routers and pallets may consult a per-destination version registry for other
+/// purposes — deciding whether to batch, or whether a feature is available — without having to
+/// wrap any message to find out.
+pub trait GetVersion {
+	/// The XCM version that `dest` is known to understand, or `None` if it is not yet known.
+	fn get_version_for(dest: &latest::MultiLocation) -> Option<u32>;
+}
+
+impl<Call> VersionedXcm<Call> {
+	/// The XCM version number of the contained message.
+	pub fn identify_version(&self) -> u32 {
+		match self {
+			Self::V0(_) => 0,
+			Self::V1(_) => 1,
+		}
+	}
+
+	/// Convert the contained message to XCM version `target`, re-wrapping it in the matching variant.
+	///
+	/// Returns `Err` if `target` is not a known version or the message cannot be expressed in it.
+	pub fn into_version(self, target: u32) -> Result<Self, ()> {
+		Ok(match target {
+			0 => Self::V0(self.try_into()?),
+			1 => Self::V1(self.try_into()?),
+			_ => return Err(()),
+		})
+	}
+}
+
 /// Convert an `Xcm` datum into a `VersionedXcm`, based on a destination `MultiLocation` which will interpret it.
 pub trait WrapVersion {
 	fn wrap_version<Call>(
@@ -269,12 +366,99 @@ impl WrapVersion for AlwaysV1 {
 	}
 }
 
+/// `WrapVersion` implementation built on top of any `GetVersion` registry: the XCM is converted to
+/// whichever version `G` reports the destination understands before wrapping. If the destination's
+/// version is unknown, the XCM is sent with whatever version it was authored as.
+pub struct WithVersion<G>(PhantomData<G>);
+impl<G: GetVersion> WrapVersion for WithVersion<G> {
+	fn wrap_version<Call>(
+		dest: &latest::MultiLocation,
+		xcm: impl Into<VersionedXcm<Call>>,
+	) -> Result<VersionedXcm<Call>, ()> {
+		Ok(match G::get_version_for(dest) {
+			Some(0) => VersionedXcm::<Call>::V0(xcm.into().try_into()?),
+			Some(1) => VersionedXcm::<Call>::V1(xcm.into().try_into()?),
+			Some(_) => return Err(()),
+			None => xcm.into(),
+		})
+	}
+}
+
+/// Hook for recording destinations whose XCM version is not yet known, together with the version
+/// to assume for them in the meantime.
+///
+/// The `SAFE_XCM_VERSION` is deliberately conservative: it is the highest version we are confident
+/// *every* destination can decode. Wrapping an unknown destination at this version guarantees the
+/// message is decodable while `note_unknown_version` lets the caller schedule a later negotiation
+/// to learn the destination's true version.
+pub trait NoteUnknownVersion {
+	/// The XCM version to fall back on for a destination whose version has not yet been learned.
+	const SAFE_XCM_VERSION: u32;
+	/// Record that the XCM version of `dest` is not yet known, so that negotiation can be
+	/// initiated later.
+	fn note_unknown_version(dest: &latest::MultiLocation);
+}
+
+/// `WrapVersion` implementation mirroring real deployment needs: consult the `Registry` for the
+/// version `dest` is known to speak and convert the XCM to exactly that version before wrapping;
+/// if the version is unknown, wrap at `Hook::SAFE_XCM_VERSION` and call
+/// `Hook::note_unknown_version` so negotiation can be kicked off.
+///
+/// The invariant upheld here is that a destination is never sent a version higher than it has
+/// advertised. Both the known-version and safe-default paths go through a `TryInto`-checked
+/// conversion, so a message that cannot be expressed in the target version yields `Err` rather
+/// than panicking.
+pub struct WrapVersionForUnknown<Registry, Hook>(PhantomData<(Registry, Hook)>);
+impl<Registry: GetVersion, Hook: NoteUnknownVersion> WrapVersion
+	for WrapVersionForUnknown<Registry, Hook>
+{
+	fn wrap_version<Call>(
+		dest: &latest::MultiLocation,
+		xcm: impl Into<VersionedXcm<Call>>,
+	) -> Result<VersionedXcm<Call>, ()> {
+		let version = match Registry::get_version_for(dest) {
+			Some(version) => version,
+			None => {
+				Hook::note_unknown_version(dest);
+				Hook::SAFE_XCM_VERSION
+			}
+		};
+		Ok(match version {
+			0 => VersionedXcm::<Call>::V0(xcm.into().try_into()?),
+			1 => VersionedXcm::<Call>::V1(xcm.into().try_into()?),
+			_ => return Err(()),
+		})
+	}
+}
+
 /// `WrapVersion` implementation which attempts to always convert the XCM to the latest version before wrapping it.
 pub type AlwaysLatest = AlwaysV1;
 
 /// `WrapVersion` implementation which attempts to always convert the XCM to the release version before wrapping it.
 pub type AlwaysRelease = AlwaysV0;
 
+/// Coordinate the XCM version-subscription protocol on behalf of a location.
+///
+/// The protocol is driven by two v1 instructions: a location sends `SubscribeVersion { query_id,
+/// max_response_weight }` to ask to be told our supported XCM version now and whenever it changes,
+/// and `UnsubscribeVersion` to cancel. On subscribe the receiver registers `(dest, query_id)` and
+/// replies with a `QueryResponse { query_id, response: Version(u32) }` carrying its current
+/// version; it replies again whenever that version changes. An implementation must:
+///
+/// - cap the number of live subscriptions, rejecting `start` with `Err` once the cap is reached;
+/// - honour `max_response_weight`, not emitting a response that would exceed it; and
+/// - wrap each `QueryResponse` at a version the subscriber is known to understand (see
+///   [`GetVersion`]) so that the reply is never undecodable.
+pub trait VersionChangeNotifier {
+	/// Start sending `dest` a `QueryResponse` bearing our XCM version, tagged with `query_id`,
+	/// now and whenever our version changes. `max_weight` bounds the weight of each response.
+	fn start(dest: &latest::MultiLocation, query_id: u64, max_weight: u64) -> Result<(), ()>;
+	/// Stop notifying `dest` of version changes.
+	fn stop(dest: &latest::MultiLocation) -> Result<(), ()>;
+	/// Whether `dest` currently has a live version subscription.
+	fn is_subscribed(dest: &latest::MultiLocation) -> bool;
+}
+
 pub mod opaque {
 	pub mod v0 {
 		// Everything from v0
@@ -0,0 +1,305 @@
+// Copyright 2020 Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Substrate is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Substrate is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Version 1 of the Cross-Consensus Message format data structures.
+
+use alloc::{boxed::Box, vec::Vec};
+use derivative::Derivative;
+use parity_scale_codec::{Decode, Encode};
+
+use super::v0::{BodyId, BodyPart, NetworkId, OriginKind};
+use crate::DoubleEncoded;
+
+/// A global identifier of an account-bearing consensus system.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Encode, Decode)]
+pub enum Junction {
+	/// An indexed parachain belonging to and operated by the context.
+	Parachain(#[codec(compact)] u32),
+	/// A 32-byte identifier for an account of a specific network that is respected as a sovereign
+	/// endpoint within the context.
+	AccountId32 { network: NetworkId, id: [u8; 32] },
+	/// An 8-byte index for an account of a specific network that is respected as a sovereign
+	/// endpoint within the context.
+	AccountIndex64 {
+		network: NetworkId,
+		#[codec(compact)]
+		index: u64,
+	},
+	/// A 20-byte identifier for an account of a specific network that is respected as a sovereign
+	/// endpoint within the context.
+	AccountKey20 { network: NetworkId, key: [u8; 20] },
+	/// An instanced, indexed pallet that forms a constituent part of the context.
+	PalletInstance(u8),
+	/// A non-descript index within the context location.
+	GeneralIndex {
+		#[codec(compact)]
+		id: u128,
+	},
+	/// A nondescript datum acting as a key within the context location.
+	GeneralKey(Vec<u8>),
+	/// The unambiguous child.
+	OnlyChild,
+	/// A pluralistic body existing within consensus.
+	Plurality { id: BodyId, part: BodyPart },
+}
+
+/// Non-parent junctions that can be constructed, up to the length of 8. This specific `Junctions`
+/// implementation uses a Rust `enum` in order to make pattern matching easier.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Encode, Decode)]
+pub enum Junctions {
+	/// The interpreting consensus system.
+	Here,
+	/// A relative path comprising 1 junction.
+	X1(Junction),
+	/// A relative path comprising 2 junctions.
+	X2(Junction, Junction),
+	/// A relative path comprising 3 junctions.
+	X3(Junction, Junction, Junction),
+	/// A relative path comprising 4 junctions.
+	X4(Junction, Junction, Junction, Junction),
+	/// A relative path comprising 5 junctions.
+	X5(Junction, Junction, Junction, Junction, Junction),
+	/// A relative path comprising 6 junctions.
+	X6(Junction, Junction, Junction, Junction, Junction, Junction),
+	/// A relative path comprising 7 junctions.
+	X7(Junction, Junction, Junction, Junction, Junction, Junction, Junction),
+	/// A relative path comprising 8 junctions.
+	X8(Junction, Junction, Junction, Junction, Junction, Junction, Junction, Junction),
+}
+
+/// A relative path between state-bearing consensus systems.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Encode, Decode)]
+pub struct MultiLocation {
+	/// The number of parent junctions at the beginning of this `MultiLocation`.
+	pub parents: u8,
+	/// The interior (i.e. non-parent) junctions that this `MultiLocation` contains.
+	pub interior: Junctions,
+}
+
+/// Classification of an asset being concrete or abstract.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Encode, Decode)]
+pub enum AssetId {
+	/// A specific location identifying an asset.
+	Concrete(MultiLocation),
+	/// An abstract identifier for an asset.
+	Abstract(Vec<u8>),
+}
+
+/// Classification of whether an asset is fungible or not, along with an optional amount or instance.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Encode, Decode)]
+pub enum Fungibility {
+	/// A fungible asset; the amount is captured.
+	Fungible(#[codec(compact)] u128),
+	/// A non-fungible asset; the instance is captured.
+	NonFungible(AssetInstance),
+}
+
+/// A general identifier for an instance of a non-fungible asset class.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Encode, Decode)]
+pub enum AssetInstance {
+	/// Undefined - used if the non-fungible asset class has only one instance.
+	Undefined,
+	/// A compact index.
+	Index(#[codec(compact)] u128),
+	/// A 4-byte fixed-length datum.
+	Array4([u8; 4]),
+	/// An 8-byte fixed-length datum.
+	Array8([u8; 8]),
+	/// A 16-byte fixed-length datum.
+	Array16([u8; 16]),
+	/// A 32-byte fixed-length datum.
+	Array32([u8; 32]),
+	/// An arbitrary piece of data.
+	Blob(Vec<u8>),
+}
+
+/// A single general identifier for an asset.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Encode, Decode)]
+pub struct MultiAsset {
+	/// The overall asset identity (aka *class*, in the case of a non-fungible).
+	pub id: AssetId,
+	/// The fungibility of the asset, which contains either the amount (in the case of a fungible
+	/// asset) or the *instance identity* (in the case of a non-fungible).
+	pub fun: Fungibility,
+}
+
+/// A collection of `MultiAsset`s, held in ascending, de-duplicated order.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Encode, Decode, Default)]
+pub struct MultiAssets(Vec<MultiAsset>);
+
+/// Classification of a wildcard over fungibility.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Encode, Decode)]
+pub enum WildFungibility {
+	/// Matches fungible assets.
+	Fungible,
+	/// Matches non-fungible assets.
+	NonFungible,
+}
+
+/// A wildcard representing a set of assets.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Encode, Decode)]
+pub enum WildMultiAsset {
+	/// All assets in the holding register, up to `usize::MAX` in count.
+	All,
+	/// All assets in the holding register of a given fungibility and ID.
+	AllOf { id: AssetId, fun: WildFungibility },
+}
+
+/// `MultiAsset` collection, either `MultiAssets` or a single wildcard.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Encode, Decode)]
+pub enum MultiAssetFilter {
+	/// Specify the filter as being everything contained by the given `MultiAssets` inner.
+	Definite(MultiAssets),
+	/// Specify the filter as the given `WildMultiAsset` wildcard.
+	Wild(WildMultiAsset),
+}
+
+/// Response data to a query.
+#[derive(Clone, Eq, PartialEq, Debug, Encode, Decode)]
+pub enum Response {
+	/// Some assets.
+	Assets(MultiAssets),
+	/// The XCM version currently supported by the responder.
+	Version(u32),
+}
+
+/// An instruction to be executed on some or all of the assets in holding, used by the asset-oriented
+/// XCM instructions.
+#[derive(Derivative, Encode, Decode)]
+#[derivative(Clone(bound = ""), Eq(bound = ""), PartialEq(bound = ""), Debug(bound = ""))]
+#[codec(encode_bound())]
+#[codec(decode_bound())]
+pub enum Order<Call> {
+	/// Do nothing. Not generally used.
+	Noop,
+	/// Remove the asset(s) (`assets`) and place equivalent assets under the ownership of
+	/// `beneficiary` within this consensus system.
+	DepositAsset { assets: MultiAssetFilter, max_assets: u32, beneficiary: MultiLocation },
+	/// Remove the asset(s) (`assets`) and place equivalent assets under the ownership of `dest`
+	/// within this consensus system, then send an onward XCM to `dest`.
+	DepositReserveAsset {
+		assets: MultiAssetFilter,
+		max_assets: u32,
+		dest: MultiLocation,
+		effects: Vec<Order<()>>,
+	},
+	/// Remove the asset(s) (`give`) and replace them with alternative assets.
+	ExchangeAsset { give: MultiAssetFilter, receive: MultiAssets },
+	/// Remove the asset(s) (`assets`) and send an onward XCM to the reserve location `reserve`.
+	InitiateReserveWithdraw {
+		assets: MultiAssetFilter,
+		reserve: MultiLocation,
+		effects: Vec<Order<()>>,
+	},
+	/// Remove the asset(s) (`assets`) and send an onward XCM to `dest` teleporting them.
+	InitiateTeleport { assets: MultiAssetFilter, dest: MultiLocation, effects: Vec<Order<()>> },
+	/// Send a `QueryResponse` XCM message bearing `Response::Assets` to `dest`, reporting the
+	/// holding of `assets`.
+	QueryHolding {
+		#[codec(compact)]
+		query_id: u64,
+		dest: MultiLocation,
+		assets: MultiAssetFilter,
+	},
+	/// Pay for the execution of some XCM with up to `weight` picoseconds of execution time, paying
+	/// for this with up to `fees` from the holding register.
+	BuyExecution {
+		fees: MultiAsset,
+		weight: u64,
+		debt: u64,
+		halt_on_error: bool,
+		instructions: Vec<Xcm<Call>>,
+	},
+}
+
+/// Cross-Consensus Message: a message from one consensus system to another.
+#[derive(Derivative, Encode, Decode)]
+#[derivative(Clone(bound = ""), Eq(bound = ""), PartialEq(bound = ""), Debug(bound = ""))]
+#[codec(encode_bound())]
+#[codec(decode_bound())]
+pub enum Xcm<Call> {
+	/// Withdraw asset(s) (`assets`) from the ownership of `origin` and place them into the holding
+	/// register, then apply the given `effects`.
+	WithdrawAsset { assets: MultiAssets, effects: Vec<Order<Call>> },
+	/// Asset(s) (`assets`) have been received into the ownership of `origin` on the reserve, then
+	/// apply the given `effects`.
+	ReserveAssetDeposited { assets: MultiAssets, effects: Vec<Order<Call>> },
+	/// Asset(s) (`assets`) have been destroyed on the `origin` system and equivalent assets should
+	/// be created and placed into the holding register, then apply the given `effects`.
+	ReceiveTeleportedAsset { assets: MultiAssets, effects: Vec<Order<Call>> },
+	/// Indication of the outcome of a previous query, identified by `query_id`.
+	QueryResponse {
+		#[codec(compact)]
+		query_id: u64,
+		response: Response,
+	},
+	/// Withdraw asset(s) (`assets`) from the ownership of `origin` and place equivalent assets
+	/// under the ownership of `beneficiary`.
+	TransferAsset { assets: MultiAssets, beneficiary: MultiLocation },
+	/// Withdraw asset(s) (`assets`) from the ownership of `origin` and place equivalent assets
+	/// under the ownership of `dest` within this consensus system, then send an onward XCM.
+	TransferReserveAsset { assets: MultiAssets, dest: MultiLocation, effects: Vec<Order<()>> },
+	/// Apply the encoded transaction `call`, whose dispatch-origin should be `origin`.
+	Transact {
+		origin_type: OriginKind,
+		require_weight_at_most: u64,
+		call: DoubleEncoded<Call>,
+	},
+	/// A message to notify about a new incoming HRMP channel.
+	HrmpNewChannelOpenRequest {
+		#[codec(compact)]
+		sender: u32,
+		#[codec(compact)]
+		max_message_size: u32,
+		#[codec(compact)]
+		max_capacity: u32,
+	},
+	/// A message to notify about that a previously sent open channel request has been accepted.
+	HrmpChannelAccepted {
+		#[codec(compact)]
+		recipient: u32,
+	},
+	/// A message to notify that the other party in an open channel decided to close it.
+	HrmpChannelClosing {
+		#[codec(compact)]
+		initiator: u32,
+		#[codec(compact)]
+		sender: u32,
+		#[codec(compact)]
+		recipient: u32,
+	},
+	/// A message to indicate that the embedded XCM is actually arriving on behalf of some inner
+	/// `who`.
+	RelayedFrom { who: MultiLocation, message: Box<Xcm<Call>> },
+	/// Ask the destination system to respond with the most recent version of XCM that it supports,
+	/// and to keep us informed whenever that version changes, tagging each response with
+	/// `query_id`. Each response must not require more than `max_response_weight` to dispatch.
+	SubscribeVersion {
+		#[codec(compact)]
+		query_id: u64,
+		#[codec(compact)]
+		max_response_weight: u64,
+	},
+	/// Cancel the effect of a previous `SubscribeVersion` instruction.
+	UnsubscribeVersion,
+}
+
+pub mod opaque {
+	/// The basic concrete type of `Xcm`, with a `Vec<u8>` held in place of call data.
+	pub type Xcm = super::Xcm<()>;
+	/// The basic concrete type of `Order`, with a `Vec<u8>` held in place of call data.
+	pub type Order = super::Order<()>;
+}